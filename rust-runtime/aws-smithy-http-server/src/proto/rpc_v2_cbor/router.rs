@@ -0,0 +1,168 @@
+/*
+ * Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ * SPDX-License-Identifier: Apache-2.0
+ */
+
+use std::convert::Infallible;
+
+use tower::Layer;
+use tower::Service;
+
+use crate::body::BoxBody;
+use crate::routers::Router;
+use crate::routing::tiny_map::TinyMap;
+use crate::routing::Route;
+
+use thiserror::Error;
+
+/// An RPC v2 CBOR routing error.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// Request path did not match the `/service/{ServiceName}/operation/{OperationName}` shape.
+    #[error("request path does not match the expected RPC v2 CBOR shape")]
+    InvalidPath,
+    /// Method was not `POST`.
+    #[error("method not POST")]
+    MethodNotAllowed,
+    /// Missing the `smithy-protocol` header.
+    #[error("missing the \"smithy-protocol\" header")]
+    MissingProtocolHeader,
+    /// The `smithy-protocol` header was present but not equal to `rpc-v2-cbor`.
+    #[error("expected the \"smithy-protocol\" header to be \"rpc-v2-cbor\"")]
+    UnexpectedProtocolHeader,
+    /// Operation not found.
+    #[error("operation not found")]
+    NotFound,
+}
+
+// This constant determines when the `TinyMap` implementation switches from being a `Vec` to a
+// `HashMap`. This is chosen to be 15 as a result of the discussion around
+// https://github.com/awslabs/smithy-rs/pull/1429#issuecomment-1147516546
+const ROUTE_CUTOFF: usize = 15;
+
+/// A [`Router`] supporting the [Smithy RPC v2 CBOR] protocol.
+///
+/// Requests are dispatched on their URI path, which must be of the form
+/// `/service/{ServiceName}/operation/{OperationName}`, rather than on a header as
+/// [`AwsJsonRouter`](crate::proto::aws_json::router::AwsJsonRouter) does.
+///
+/// [Smithy RPC v2 CBOR]: https://smithy.io/2.0/additional-specs/protocols/smithy-rpc-v2.html
+#[derive(Debug, Clone)]
+pub struct RpcV2CborRouter<S> {
+    routes: TinyMap<String, S, ROUTE_CUTOFF>,
+}
+
+impl<S> RpcV2CborRouter<S> {
+    /// Applies a [`Layer`] uniformly to all routes.
+    pub fn layer<L>(self, layer: L) -> RpcV2CborRouter<L::Service>
+    where
+        L: Layer<S>,
+    {
+        RpcV2CborRouter {
+            routes: self
+                .routes
+                .into_iter()
+                .map(|(key, route)| (key, layer.layer(route)))
+                .collect(),
+        }
+    }
+
+    /// Applies type erasure to the inner route using [`Route::new`].
+    pub fn boxed<B>(self) -> RpcV2CborRouter<Route<B>>
+    where
+        S: Service<http::Request<B>, Response = http::Response<BoxBody>, Error = Infallible>,
+        S: Send + Clone + 'static,
+        S::Future: Send + 'static,
+    {
+        RpcV2CborRouter {
+            routes: self.routes.into_iter().map(|(key, s)| (key, Route::new(s))).collect(),
+        }
+    }
+}
+
+/// Parses the `{service}#{operation}` shape id out of a `/service/{ServiceName}/operation/{OperationName}`
+/// request path. Returns `None` if the path doesn't match that shape.
+fn parse_shape_id(path: &str) -> Option<String> {
+    let mut segments = path.trim_start_matches('/').split('/');
+    match (
+        segments.next(),
+        segments.next(),
+        segments.next(),
+        segments.next(),
+        segments.next(),
+    ) {
+        (Some("service"), Some(service), Some("operation"), Some(operation), None)
+            if !service.is_empty() && !operation.is_empty() =>
+        {
+            Some(format!("{}#{}", service, operation))
+        }
+        _ => None,
+    }
+}
+
+impl<B, S> Router<B> for RpcV2CborRouter<S>
+where
+    S: Clone,
+{
+    type Service = S;
+    type Error = Error;
+
+    fn match_route(&self, request: &http::Request<B>) -> Result<S, Self::Error> {
+        // Only `Method::POST` is allowed.
+        if request.method() != http::Method::POST {
+            return Err(Error::MethodNotAllowed);
+        }
+
+        // The `smithy-protocol` header must be present and equal to `rpc-v2-cbor`.
+        let protocol = request
+            .headers()
+            .get("smithy-protocol")
+            .ok_or(Error::MissingProtocolHeader)?;
+        if protocol != "rpc-v2-cbor" {
+            return Err(Error::UnexpectedProtocolHeader);
+        }
+
+        // Parse the `{service}#{operation}` shape id out of the request path.
+        let shape_id = parse_shape_id(request.uri().path()).ok_or(Error::InvalidPath)?;
+
+        // Lookup in the `TinyMap` for a route for the shape id.
+        let route = self.routes.get(&shape_id).ok_or(Error::NotFound)?;
+        Ok(route.clone())
+    }
+}
+
+impl<S> FromIterator<(String, S)> for RpcV2CborRouter<S> {
+    #[inline]
+    fn from_iter<T: IntoIterator<Item = (String, S)>>(iter: T) -> Self {
+        Self {
+            routes: iter
+                .into_iter()
+                .map(|(shape_id, request_spec)| (shape_id, request_spec))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_valid_paths() {
+        assert_eq!(
+            parse_shape_id("/service/MyService/operation/MyOperation"),
+            Some("MyService#MyOperation".to_string())
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_paths() {
+        assert_eq!(parse_shape_id("/"), None);
+        assert_eq!(parse_shape_id("/service/MyService"), None);
+        assert_eq!(parse_shape_id("/service//operation/MyOperation"), None);
+        assert_eq!(
+            parse_shape_id("/service/MyService/operation/MyOperation/extra"),
+            None
+        );
+    }
+}
@@ -4,6 +4,7 @@
  */
 
 use crate::endpoint_lib::diagnostic::DiagnosticCollector;
+use crate::endpoint_lib::idna::to_ascii_label;
 
 pub(crate) fn is_valid_host_label(
     label: &str,
@@ -11,6 +12,15 @@ pub(crate) fn is_valid_host_label(
     e: &mut DiagnosticCollector,
 ) -> bool {
     if allow_dots {
+        // RFC 1035 caps a fully-qualified name at 255 octets on the wire, which works out to
+        // 253 octets in textual form (the wire form adds a length-prefix byte per label and a
+        // terminating root zero-length label). Per-part validation below only catches labels
+        // that individually exceed 63 octets, so a name built of many valid labels could still
+        // be too long overall without this check.
+        if label.len() > 253 {
+            e.report_error("host name too long");
+            return false;
+        }
         for part in label.split('.') {
             if !is_valid_host_label(part, false, e) {
                 return false;
@@ -32,6 +42,51 @@ pub(crate) fn is_valid_host_label(
     }
 }
 
+/// Like [`is_valid_host_label`], but additionally accepts internationalized labels by first
+/// converting them to their ASCII-Compatible Encoding ([IDNA] ToASCII / Punycode) and validating
+/// the 63-octet and leading-`-` rules against the *encoded* form, since that's what actually goes
+/// out over the wire to DNS. Returns the canonicalized (ASCII) label on success.
+///
+/// [IDNA]: https://www.unicode.org/reports/tr46/
+pub(crate) fn is_valid_host_label_idna(
+    label: &str,
+    allow_dots: bool,
+    e: &mut DiagnosticCollector,
+) -> Option<String> {
+    if allow_dots {
+        let mut encoded_parts = Vec::new();
+        for part in label.split('.') {
+            encoded_parts.push(encode_or_report(part, e)?);
+        }
+        let joined = encoded_parts.join(".");
+        // Delegate to `is_valid_host_label`'s own `allow_dots` branch so both the per-label
+        // 63-octet check and the cumulative 253-octet RFC 1035 check run against the encoded
+        // form, instead of duplicating (and forgetting to duplicate) that logic here.
+        if is_valid_host_label(&joined, true, e) {
+            Some(joined)
+        } else {
+            None
+        }
+    } else {
+        let ascii_label = encode_or_report(label, e)?;
+        if is_valid_host_label(&ascii_label, false, e) {
+            Some(ascii_label)
+        } else {
+            None
+        }
+    }
+}
+
+fn encode_or_report(label: &str, e: &mut DiagnosticCollector) -> Option<String> {
+    match to_ascii_label(label) {
+        Some(ascii_label) => Some(ascii_label),
+        None => {
+            e.report_error("host label could not be converted to ASCII");
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use proptest::proptest;
@@ -57,6 +112,20 @@ mod test {
         );
     }
 
+    #[test]
+    fn total_name_length_is_enforced() {
+        // Four 63-octet labels joined by dots: each label is individually valid, but the
+        // overall name (4 * 63 + 3 dots = 255 octets) exceeds the 253 octet RFC 1035 limit.
+        let too_long = format!("{0}.{0}.{0}.{0}", "a".repeat(63));
+        assert_eq!(too_long.len(), 255);
+        assert_eq!(is_valid_host_label(&too_long, true), false);
+
+        // Drop two octets to land exactly on the 253 octet limit, which should still pass.
+        let at_limit = format!("{0}.{0}.{0}.{1}", "a".repeat(63), "a".repeat(61));
+        assert_eq!(at_limit.len(), 253);
+        assert_eq!(is_valid_host_label(&at_limit, true), true);
+    }
+
     #[test]
     fn start_bounds() {
         assert_eq!(is_valid_host_label("-foo", false), false);
@@ -73,4 +142,50 @@ mod test {
             is_valid_host_label(&s, dots);
         }
     }
+
+    fn is_valid_host_label_idna(label: &str, allow_dots: bool) -> Option<String> {
+        super::is_valid_host_label_idna(label, allow_dots, &mut DiagnosticCollector::new())
+    }
+
+    #[test]
+    fn idna_encodes_non_ascii_labels() {
+        assert_eq!(
+            is_valid_host_label_idna("münchen", false).as_deref(),
+            Some("xn--mnchen-3ya")
+        );
+        assert_eq!(
+            is_valid_host_label_idna("münchen.example.com", true).as_deref(),
+            Some("xn--mnchen-3ya.example.com")
+        );
+    }
+
+    #[test]
+    fn idna_does_not_double_encode_already_encoded_labels() {
+        assert_eq!(
+            is_valid_host_label_idna("xn--mnchen-3ya", false).as_deref(),
+            Some("xn--mnchen-3ya")
+        );
+    }
+
+    #[test]
+    fn idna_rejects_empty_and_combining_only_labels() {
+        assert_eq!(is_valid_host_label_idna("", false), None);
+        assert_eq!(is_valid_host_label_idna("\u{0301}", false), None);
+    }
+
+    #[test]
+    fn idna_bounds_are_checked_on_the_encoded_form() {
+        // A label that's short in character count but expands past 63 octets once encoded.
+        let huge_label: String = std::iter::repeat('\u{00e9}').take(60).collect();
+        assert_eq!(is_valid_host_label_idna(&huge_label, false), None);
+    }
+
+    #[test]
+    fn idna_enforces_total_name_length_across_dotted_labels() {
+        // Each individual label is well under the 63-octet limit once encoded, but six of them
+        // joined by dots blow past the 253-octet RFC 1035 limit (341 octets total).
+        let part: String = std::iter::repeat('\u{00e9}').take(50).collect();
+        let name = [part.as_str(); 6].join(".");
+        assert_eq!(is_valid_host_label_idna(&name, true), None);
+    }
 }
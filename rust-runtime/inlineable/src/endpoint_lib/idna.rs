@@ -0,0 +1,73 @@
+/*
+ *  Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *  SPDX-License-Identifier: Apache-2.0
+ */
+
+//! Minimal ToASCII conversion ([IDNA]) for a single, already-split host label, built on top of
+//! [`crate::endpoint_lib::punycode`]. This only implements the subset of IDNA needed to turn an
+//! internationalized label into a DNS-safe `xn--` A-label; it does not perform full Unicode
+//! normalization.
+//!
+//! [IDNA]: https://www.unicode.org/reports/tr46/
+
+use crate::endpoint_lib::punycode;
+
+const ACE_PREFIX: &str = "xn--";
+
+/// Converts `label` to its ASCII-Compatible Encoding (ACE) form.
+///
+/// If `label` is already all-ASCII (including an already-encoded `xn--` label), it is returned
+/// unchanged so that we never double-encode. Otherwise, the label is Punycode-encoded and
+/// prefixed with `xn--`.
+///
+/// Returns `None` if the label cannot be encoded, e.g. because it's empty or consists entirely
+/// of combining marks (which have no meaning without a preceding base character).
+pub(crate) fn to_ascii_label(label: &str) -> Option<String> {
+    if label.is_empty() || is_all_combining_marks(label) {
+        return None;
+    }
+    if label.is_ascii() {
+        return Some(label.to_string());
+    }
+    let encoded = punycode::encode(label).ok()?;
+    Some(format!("{}{}", ACE_PREFIX, encoded))
+}
+
+/// A pragmatic check for "this label is made up entirely of combining marks", which IDNA
+/// considers invalid since a combining mark can't stand on its own without a base character.
+/// This only covers the common combining mark blocks rather than the full Unicode `Mn`/`Mc`
+/// general categories, which would require pulling in Unicode tables this crate doesn't have.
+fn is_all_combining_marks(label: &str) -> bool {
+    label.chars().all(|ch| {
+        let cp = ch as u32;
+        matches!(cp,
+            0x0300..=0x036F // Combining Diacritical Marks
+            | 0x1AB0..=0x1AFF // Combining Diacritical Marks Extended
+            | 0x1DC0..=0x1DFF // Combining Diacritical Marks Supplement
+            | 0x20D0..=0x20FF // Combining Diacritical Marks for Symbols
+            | 0xFE20..=0xFE2F // Combining Half Marks
+        )
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ascii_passes_through_unchanged() {
+        assert_eq!(to_ascii_label("example").as_deref(), Some("example"));
+        assert_eq!(to_ascii_label("xn--mnchen-3ya").as_deref(), Some("xn--mnchen-3ya"));
+    }
+
+    #[test]
+    fn non_ascii_is_punycode_encoded() {
+        assert_eq!(to_ascii_label("münchen").as_deref(), Some("xn--mnchen-3ya"));
+    }
+
+    #[test]
+    fn empty_and_combining_only_labels_fail() {
+        assert_eq!(to_ascii_label(""), None);
+        assert_eq!(to_ascii_label("\u{0301}"), None);
+    }
+}
@@ -0,0 +1,145 @@
+/*
+ *  Copyright Amazon.com, Inc. or its affiliates. All Rights Reserved.
+ *  SPDX-License-Identifier: Apache-2.0
+ */
+
+//! A small, dependency-free implementation of the Punycode algorithm ([RFC 3492]) used to
+//! produce the ASCII-Compatible Encoding (ACE) of an internationalized host label, e.g.
+//! `münchen` becomes `xn--mnchen-3ya`.
+//!
+//! This is implemented from scratch (rather than pulling in the `idna`/`punycode` crates)
+//! because this module is inlined verbatim into every generated SDK, and we don't want to
+//! impose an extra dependency on every customer for the sake of a host label edge case.
+//!
+//! [RFC 3492]: https://www.rfc-editor.org/rfc/rfc3492
+
+const BASE: u32 = 36;
+const TMIN: u32 = 1;
+const TMAX: u32 = 26;
+const SKEW: u32 = 38;
+const DAMP: u32 = 700;
+const INITIAL_BIAS: u32 = 72;
+const INITIAL_N: u32 = 128;
+
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum PunycodeError {
+    /// The input contained no code points at all.
+    EmptyInput,
+    /// The input is too large to encode (guards against pathological overflow).
+    Overflow,
+}
+
+/// Encodes `input` (which must contain at least one non-ASCII code point) into the
+/// Punycode form, *without* the `xn--` ACE prefix.
+pub(crate) fn encode(input: &str) -> Result<String, PunycodeError> {
+    if input.is_empty() {
+        return Err(PunycodeError::EmptyInput);
+    }
+
+    let code_points: Vec<u32> = input.chars().map(|ch| ch as u32).collect();
+    let basic_code_points: Vec<u32> = code_points.iter().copied().filter(|cp| *cp < 0x80).collect();
+
+    let mut output = String::new();
+    for cp in &basic_code_points {
+        output.push(char::from_u32(*cp).expect("basic code points are valid ASCII"));
+    }
+    let mut h = basic_code_points.len();
+    let b = basic_code_points.len();
+    if b > 0 {
+        output.push('-');
+    }
+
+    let mut n = INITIAL_N;
+    let mut delta: u32 = 0;
+    let mut bias = INITIAL_BIAS;
+
+    while h < code_points.len() {
+        let m = code_points
+            .iter()
+            .copied()
+            .filter(|cp| *cp >= n)
+            .min()
+            .ok_or(PunycodeError::Overflow)?;
+
+        delta = delta
+            .checked_add((m - n).checked_mul(h as u32 + 1).ok_or(PunycodeError::Overflow)?)
+            .ok_or(PunycodeError::Overflow)?;
+        n = m;
+
+        for cp in &code_points {
+            if *cp < n {
+                delta = delta.checked_add(1).ok_or(PunycodeError::Overflow)?;
+            }
+            if *cp == n {
+                let mut q = delta;
+                let mut k = BASE;
+                loop {
+                    let t = if k <= bias {
+                        TMIN
+                    } else if k >= bias + TMAX {
+                        TMAX
+                    } else {
+                        k - bias
+                    };
+                    if q < t {
+                        break;
+                    }
+                    let digit = t + (q - t) % (BASE - t);
+                    output.push(digit_to_basic(digit));
+                    q = (q - t) / (BASE - t);
+                    k += BASE;
+                }
+                output.push(digit_to_basic(q));
+                bias = adapt(delta, h as u32 + 1, h == b);
+                delta = 0;
+                h += 1;
+            }
+        }
+        delta += 1;
+        n += 1;
+    }
+
+    Ok(output)
+}
+
+fn digit_to_basic(digit: u32) -> char {
+    let value = if digit < 26 {
+        digit + b'a' as u32
+    } else {
+        digit - 26 + b'0' as u32
+    };
+    char::from_u32(value).expect("digit_to_basic only ever produces ASCII alphanumerics")
+}
+
+fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+    let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+    delta += delta / num_points;
+
+    let mut k = 0;
+    while delta > ((BASE - TMIN) * TMAX) / 2 {
+        delta /= BASE - TMIN;
+        k += BASE;
+    }
+    k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn encodes_known_vectors() {
+        // Known Punycode encodings, without the `xn--` ACE prefix.
+        assert_eq!(encode("münchen").unwrap(), "mnchen-3ya");
+        assert_eq!(encode("ü").unwrap(), "tda");
+        assert_eq!(
+            encode("¡Porqué no puedén simplemente hablar en Español").unwrap(),
+            "Porqu no puedn simplemente hablar en Espaol-rgb953aia81b"
+        );
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert_eq!(encode(""), Err(PunycodeError::EmptyInput));
+    }
+}
@@ -20,6 +20,20 @@ pub struct CodeGenSettings {
     pub max_gradle_metaspace_megabytes: usize,
     pub aws_models_path: Option<PathBuf>,
     pub model_metadata_path: Option<PathBuf>,
+    /// When set, only these services are generated (passed to Gradle as `-Paws.services`)
+    /// instead of the full SDK. This is much faster for iterating on a single service, but the
+    /// resulting `aws-sdk` build output will only contain the filtered set of crates.
+    pub services_filter: Option<Vec<String>>,
+    /// When set to a shared directory, Gradle's local build cache and Kotlin's incremental
+    /// compilation are re-enabled and pointed at that directory, so that repeated local
+    /// generations can reuse work from previous ones.
+    ///
+    /// This trades away the reproducibility guarantee that the default (`None`, cache-disabled)
+    /// settings provide -- a stale or corrupted cache entry could, in principle, produce output
+    /// that doesn't match a clean generation of the same commit. It should stay `None` for the CI
+    /// sync path, where every generation must be reproducible from scratch, and can be set for
+    /// local developer workflows where speed matters more than that guarantee.
+    pub reuse_build_cache: Option<PathBuf>,
 }
 
 impl Default for CodeGenSettings {
@@ -30,10 +44,29 @@ impl Default for CodeGenSettings {
             max_gradle_metaspace_megabytes: 512,
             aws_models_path: None,
             model_metadata_path: None,
+            services_filter: None,
+            reuse_build_cache: None,
         }
     }
 }
 
+impl CodeGenSettings {
+    /// Restricts codegen to the given set of services instead of assembling the full SDK.
+    pub fn services_filter(mut self, services: Vec<String>) -> Self {
+        self.services_filter = Some(services);
+        self
+    }
+
+    /// Enables Gradle build-cache reuse across generations, pointed at `build_cache_path`.
+    ///
+    /// See the [`CodeGenSettings::reuse_build_cache`] docs for the reproducibility tradeoff this
+    /// implies.
+    pub fn reuse_build_cache(mut self, build_cache_path: impl Into<PathBuf>) -> Self {
+        self.reuse_build_cache = Some(build_cache_path.into());
+        self
+    }
+}
+
 pub struct GeneratedSdk {
     path: PathBuf,
     // Keep a reference to the temp directory so that it doesn't get cleaned up
@@ -208,15 +241,58 @@ impl DefaultSdkGenerator {
                 ),
                 "-XX:+UseSerialGC".to_string(),
                 "-verbose:gc".to_string(),
-                // Disable incremental compilation and caching since we're compiling exactly once per commit
-                "-Dkotlin.incremental=false".to_string(),
-                "-Dkotlin.caching.enabled=false".to_string(),
+                // Normally, incremental compilation and caching are disabled since we're
+                // compiling exactly once per commit and need that commit's generation to be
+                // reproducible. `reuse_build_cache` opts into re-enabling them for local
+                // developer workflows where speed matters more than that guarantee.
+                format!(
+                    "-Dkotlin.incremental={}",
+                    self.settings.reuse_build_cache.is_some()
+                ),
+                format!(
+                    "-Dkotlin.caching.enabled={}",
+                    self.settings.reuse_build_cache.is_some()
+                ),
                 // Run the compiler in the gradle daemon process to avoid more forking thrash
                 "-Dkotlin.compiler.execution.strategy=in-process".to_string()
             ]
             .join(" ")
         ));
 
+        // `--build-cache` alone only turns caching on; the directory it's stored in is
+        // configured via `settingsEvaluated` in a Gradle init script, since there's no CLI flag
+        // or system property for just the local build cache directory. We deliberately don't use
+        // `--gradle-user-home`, as that would relocate the *entire* Gradle user home (dependency
+        // cache, wrapper distributions, daemon registry, plugin cache), not just the build cache.
+        let _build_cache_init_script =
+            if let Some(build_cache_path) = &self.settings.reuse_build_cache {
+                let init_script = tempfile::NamedTempFile::new().context(here!())?;
+                std::fs::write(
+                    init_script.path(),
+                    format!(
+                        r#"settingsEvaluated {{ settings ->
+    settings.buildCache {{
+        local {{
+            directory = "{}"
+            enabled = true
+        }}
+    }}
+}}
+"#,
+                        build_cache_path
+                            .to_str()
+                            .expect("build cache path is a valid str")
+                    ),
+                )
+                .context(here!())?;
+                command.arg("--build-cache");
+                command.arg("--init-script");
+                command.arg(init_script.path());
+                Some(init_script)
+            } else {
+                None
+            };
+
         // Disable Smithy's codegen parallelism in favor of sdk-sync parallelism
         command.arg(format!(
             "-Djava.util.concurrent.ForkJoinPool.common.parallelism={}",
@@ -239,6 +315,9 @@ impl DefaultSdkGenerator {
                     .expect("model metadata path is a valid str")
             ));
         }
+        if let Some(services_filter) = &self.settings.services_filter {
+            command.arg(format!("-Paws.services={}", services_filter.join(",")));
+        }
         command.arg(format!(
             "-Paws.sdk.previous.release.versions.manifest={}",
             self.previous_versions_manifest